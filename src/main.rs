@@ -1,24 +1,47 @@
 use bevy::{
+    core_pipeline::{
+        bloom::{Bloom, BloomCompositeMode},
+        tonemapping::Tonemapping,
+    },
+    input::mouse::{MouseMotion, MouseWheel},
     math::bounding::{BoundingSphere, IntersectsVolume, RayCast3d},
     prelude::*,
     render::{
         render_asset::RenderAssetUsages,
         render_resource::{Extent3d, TextureDimension, TextureFormat},
     },
+    window::{CursorGrabMode, PrimaryWindow},
 };
 use rand::Rng;
 
+use std::collections::HashMap;
+use std::time::Duration;
 use std::f32::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, PI, TAU};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
         .init_gizmo_group::<DefaultGizmoConfigGroup>()
+        .init_state::<CameraMode>()
+        .add_event::<FeedbackEvent>()
         .add_systems(
             Startup,
             (create_assets, (spawn_camera, spawn_initial_targets)).chain(),
         )
-        .add_systems(Update, (camera_control, aim_check))
+        .add_systems(Startup, grab_cursor)
+        .add_systems(
+            Update,
+            (
+                camera_control,
+                camera_zoom,
+                toggle_camera_mode,
+                toggle_cursor_grab,
+                aim_check,
+                play_feedback,
+                update_pops,
+                advance_chord,
+            ),
+        )
         .add_systems(FixedUpdate, normalize_aim)
         .run();
 }
@@ -26,6 +49,10 @@ fn main() {
 #[derive(Bundle, Default)]
 struct MyCameraBundle {
     camera: Camera3d,
+    render: Camera,
+    tonemapping: Tonemapping,
+    bloom: Bloom,
+    projection: Projection,
     transform: Transform,
     state: CameraAimState,
     settings: CameraSettings,
@@ -33,20 +60,48 @@ struct MyCameraBundle {
 
 #[derive(Component)]
 struct CameraSettings {
-    // fov: u16,
     pos: Vec3,
     pitch_rate: f32,
     roll_rate: f32,
+    sensitivity: f32,
+    bloom_intensity: f32,
+    clear_color: Color,
+    fov: f32,
+    fov_min: f32,
+    fov_max: f32,
+    zoom_rate: f32,
     pitch_up_key: KeyCode,
     pitch_down_key: KeyCode,
     roll_left_key: KeyCode,
     roll_right_key: KeyCode,
+    cursor_toggle_key: KeyCode,
+    mode_toggle_key: KeyCode,
+    freefly_left_key: KeyCode,
+    freefly_right_key: KeyCode,
+    freefly_up_key: KeyCode,
+    freefly_down_key: KeyCode,
+    freefly_fwd_key: KeyCode,
+    freefly_back_key: KeyCode,
+    freefly_mov_rate: f32,
+}
+
+/// Which control scheme `camera_control` applies.
+///
+/// `Gameplay` is the locked aiming camera; `FreeFly` adds WASD-style translation
+/// for inspecting the scene, toggled with [`CameraSettings::mode_toggle_key`].
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum CameraMode {
+    #[default]
+    Gameplay,
+    FreeFly,
 }
 
 #[derive(Component)]
 struct CameraAimState {
     pitch: f32,
     roll: f32,
+    /// Field of view the zoom system lerps the perspective projection toward.
+    target_fov: f32,
 }
 
 #[derive(Component)]
@@ -74,6 +129,82 @@ enum TargetState {
     Ghost,
 }
 
+/// Gameplay events that drive audio/visual feedback, emitted by [`aim_check`].
+#[derive(Event)]
+enum FeedbackEvent {
+    TargetHit { at: Vec3 },
+    TargetMissed,
+    Combo { count: u32 },
+}
+
+/// Coarse category used to look up a clip pool for a [`FeedbackEvent`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum FeedbackCategory {
+    Hit,
+    Miss,
+    Combo,
+}
+
+impl FeedbackCategory {
+    fn of(event: &FeedbackEvent) -> Self {
+        match event {
+            FeedbackEvent::TargetHit { .. } => FeedbackCategory::Hit,
+            FeedbackEvent::TargetMissed => FeedbackCategory::Miss,
+            FeedbackEvent::Combo { .. } => FeedbackCategory::Combo,
+        }
+    }
+}
+
+/// Small pool of clips per category so repeated events draw from several sounds.
+#[derive(Resource)]
+struct FeedbackAssets {
+    clips: HashMap<FeedbackCategory, Vec<Handle<AudioSource>>>,
+}
+
+/// Running hit streak; reset when the Active target is missed.
+#[derive(Resource, Default)]
+struct ComboState {
+    count: u32,
+}
+
+/// How long the Active target may dwell unhit before a [`FeedbackEvent::TargetMissed`] fires.
+#[derive(Resource)]
+struct ActiveDwell {
+    timer: Timer,
+}
+
+impl Default for ActiveDwell {
+    fn default() -> Self {
+        ActiveDwell {
+            timer: Timer::from_seconds(MISS_TIMEOUT_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Short-lived "pop" mesh spawned at a hit point; scales up then despawns.
+#[derive(Component)]
+struct Pop {
+    timer: Timer,
+}
+
+/// Tempo driving how fast the chord progression advances.
+#[derive(Resource)]
+struct Bpm(f32);
+
+/// A chord as the set of scale degrees (0-6) it allows notes to be drawn from.
+struct Chord {
+    degrees: Vec<u8>,
+}
+
+/// The gated chord progression that shapes where targets spawn and what plays underneath.
+#[derive(Resource)]
+struct MusicState {
+    progression: Vec<Chord>,
+    index: usize,
+    timer: Timer,
+    chord_audio: Vec<Handle<AudioSource>>,
+}
+
 #[derive(Resource)]
 struct MyAssets {
     debug_material: Handle<StandardMaterial>,
@@ -96,14 +227,29 @@ impl Default for Shape {
 impl Default for CameraSettings {
     fn default() -> Self {
         CameraSettings {
-            // fov: 90,
             pos: Vec3::ZERO,
             pitch_rate: 0.01,
             roll_rate: 0.02,
+            sensitivity: RADIANS_PER_DOT,
+            bloom_intensity: 0.3,
+            clear_color: Color::BLACK,
+            fov: FRAC_PI_4,
+            fov_min: 0.2,
+            fov_max: FRAC_PI_2,
+            zoom_rate: 0.05,
             pitch_up_key: KeyCode::ArrowUp,
             pitch_down_key: KeyCode::ArrowDown,
             roll_left_key: KeyCode::ArrowLeft,
             roll_right_key: KeyCode::ArrowRight,
+            cursor_toggle_key: KeyCode::Escape,
+            mode_toggle_key: KeyCode::KeyC,
+            freefly_left_key: KeyCode::KeyA,
+            freefly_right_key: KeyCode::KeyT,
+            freefly_up_key: KeyCode::KeyS,
+            freefly_down_key: KeyCode::KeyR,
+            freefly_fwd_key: KeyCode::KeyF,
+            freefly_back_key: KeyCode::KeyD,
+            freefly_mov_rate: 0.05,
         }
     }
 }
@@ -113,15 +259,34 @@ impl Default for CameraAimState {
         CameraAimState {
             pitch: 0.0,
             roll: 0.0,
+            target_fov: FRAC_PI_4,
         }
     }
 }
 
+/// Mouse-look scale: radians of aim rotation per unit of mouse motion ("dot").
+const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
+
+/// Every Nth consecutive hit fires a distinct combo sound.
+const COMBO_MILESTONE: u32 = 5;
+
+/// Seconds the Active target may dwell unhit before it counts as a miss.
+const MISS_TIMEOUT_SECS: f32 = 3.0;
+
+/// Notes per octave in the scale degrees are drawn from.
+const SCALE_DEGREES: f32 = 7.0;
+/// How many octaves map onto distinct elevation bands.
+const OCTAVE_SPAN: u8 = 3;
+/// Total vertical spread of target elevations, centered on the horizon.
+const ELEVATION_RANGE: f32 = FRAC_PI_2;
+
 const MAX_RADIUS: f32 = 20.0;
 const TARGET_RADIUS: f32 = 1.;
 const TARGET_DISTANCE: f32 = 8.;
 const DEADZONE_RADIUS_SQUARED: f32 = 4.;
 const DEADZONE_ADJ_THETA: f32 = -0.02;
+/// How many fresh note samples to try before falling back to nudging the direction.
+const DEADZONE_MAX_RESAMPLES: u32 = 16;
 
 fn create_assets(
     mut commands: Commands,
@@ -142,13 +307,20 @@ fn create_assets(
         TextureFormat::Rgba8UnormSrgb,
         RenderAssetUsages::RENDER_WORLD,
     );
+    // Crosshair glows hot so it reads as a neon reticle against the dark clear color.
     let debug_material = materials.add(StandardMaterial {
         base_color_texture: Some(images.add(texture)),
+        emissive: LinearRgba::rgb(6.0, 0.0, 4.0),
         ..default()
     });
 
+    // Active target is emissive and blooms; the faded Next/Ghost variant stays dim.
     let arrow_texture: Handle<Image> = external_assets.load("arrow.png");
-    let arrow_material = materials.add(arrow_texture);
+    let arrow_material = materials.add(StandardMaterial {
+        base_color_texture: Some(arrow_texture),
+        emissive: LinearRgba::rgb(0.0, 4.0, 6.0),
+        ..default()
+    });
 
     let arrow_faded_texture: Handle<Image> = external_assets.load("arrow_faded.png");
     let arrow_faded_material = materials.add(arrow_faded_texture);
@@ -162,6 +334,66 @@ fn create_assets(
         arrow: arrow_material,
         arrow_faded: arrow_faded_material,
     });
+
+    // A small pool per category so repeated hits/combos vary between clips; the pick is random,
+    // so the same clip can still play twice in a row.
+    let mut clips = HashMap::new();
+    clips.insert(
+        FeedbackCategory::Hit,
+        vec![
+            external_assets.load("sfx/hit_0.ogg"),
+            external_assets.load("sfx/hit_1.ogg"),
+            external_assets.load("sfx/hit_2.ogg"),
+        ],
+    );
+    clips.insert(
+        FeedbackCategory::Miss,
+        vec![external_assets.load("sfx/miss_0.ogg")],
+    );
+    clips.insert(
+        FeedbackCategory::Combo,
+        vec![
+            external_assets.load("sfx/combo_0.ogg"),
+            external_assets.load("sfx/combo_1.ogg"),
+        ],
+    );
+    commands.insert_resource(FeedbackAssets { clips });
+    commands.init_resource::<ComboState>();
+    commands.init_resource::<ActiveDwell>();
+
+    // I–vi–IV–V in a major scale; each chord gates the degrees the next note may use.
+    let bpm = 120.0;
+    let bar_seconds = 4.0 * 60.0 / bpm;
+    let progression = vec![
+        Chord {
+            degrees: vec![0, 2, 4],
+        },
+        Chord {
+            degrees: vec![5, 0, 2],
+        },
+        Chord {
+            degrees: vec![3, 5, 0],
+        },
+        Chord {
+            degrees: vec![4, 6, 1],
+        },
+    ];
+    let chord_audio = vec![
+        external_assets.load("music/chord_i.ogg"),
+        external_assets.load("music/chord_vi.ogg"),
+        external_assets.load("music/chord_iv.ogg"),
+        external_assets.load("music/chord_v.ogg"),
+    ];
+    // Play the opening chord immediately so the music is coupled to the layout from frame
+    // one, rather than staying silent until the progression wraps back to index 0.
+    commands.spawn((AudioPlayer(chord_audio[0].clone()), PlaybackSettings::DESPAWN));
+    commands.insert_resource(Bpm(bpm));
+    commands.insert_resource(MusicState {
+        progression,
+        index: 0,
+        timer: Timer::from_seconds(bar_seconds, TimerMode::Repeating),
+        chord_audio,
+    });
 }
 
 fn draw_gizmos(
@@ -197,6 +429,23 @@ fn spawn_camera(
     let mut camera = MyCameraBundle::default();
     camera.transform.translation = camera.settings.pos;
 
+    // HDR + tonemapping + bloom so emissive targets and the crosshair glow.
+    camera.render.hdr = true;
+    camera.render.clear_color = ClearColorConfig::Custom(camera.settings.clear_color);
+    camera.tonemapping = Tonemapping::TonyMcMapface;
+    camera.bloom = Bloom {
+        intensity: camera.settings.bloom_intensity,
+        composite_mode: BloomCompositeMode::EnergyConserving,
+        ..default()
+    };
+
+    // Explicit perspective projection so the mouse-wheel zoom can drive the FOV.
+    camera.state.target_fov = camera.settings.fov;
+    camera.projection = Projection::Perspective(PerspectiveProjection {
+        fov: camera.settings.fov,
+        ..default()
+    });
+
     let xhair_mesh = CircularSector::new(0.2, (PI * 3.) / 4.);
     let xhair = meshes.add(xhair_mesh);
     let crosshair = Shape {
@@ -215,14 +464,19 @@ fn spawn_camera(
 // Need pointer or other hint leading to active target. This code as-is can be spawning the next target, but we'll need to spawn
 // two at game-loop start.
 // Actually do we need three targets? Active, Next, & Ghost? This way we can orient Next before it becomes active.
-fn spawn_initial_targets(mut commands: Commands, my_assets: Res<MyAssets>) {
-    let ghost_pos = spawn_target(&mut commands, &my_assets, TargetState::Ghost, None, None);
+fn spawn_initial_targets(
+    mut commands: Commands,
+    my_assets: Res<MyAssets>,
+    music: Res<MusicState>,
+) {
+    let ghost_pos = spawn_target(&mut commands, &my_assets, TargetState::Ghost, None, None, &music);
     let next_pos = spawn_target(
         &mut commands,
         &my_assets,
         TargetState::Next,
         Some(ghost_pos),
         Some(ghost_pos),
+        &music,
     );
     let _active_pos = spawn_target(
         &mut commands,
@@ -230,15 +484,26 @@ fn spawn_initial_targets(mut commands: Commands, my_assets: Res<MyAssets>) {
         TargetState::Active,
         Some(next_pos),
         Some(next_pos),
+        &music,
     );
 }
 
-fn random_normalized_vec3() -> Vec3 {
+/// Sample the next note from the current chord and map it to a unit spawn direction.
+///
+/// Scale degree sets the azimuth around the player; octave sets the elevation band.
+fn next_note_direction(music: &MusicState) -> Vec3 {
     let mut gen = rand::thread_rng();
+    let chord = &music.progression[music.index];
+    let degree = chord.degrees[gen.gen_range(0..chord.degrees.len())];
+    let octave = gen.gen_range(0..OCTAVE_SPAN);
+
+    let azimuth = (degree as f32 / SCALE_DEGREES) * TAU;
+    let elevation = (octave as f32 / (OCTAVE_SPAN - 1) as f32 - 0.5) * ELEVATION_RANGE;
+
     Vec3::new(
-        gen.gen_range(-1.0..1.0),
-        gen.gen_range(-1.0..1.0),
-        gen.gen_range(-1.0..1.0),
+        elevation.cos() * azimuth.cos(),
+        elevation.sin(),
+        elevation.cos() * azimuth.sin(),
     )
     .normalize()
 }
@@ -246,23 +511,20 @@ fn random_normalized_vec3() -> Vec3 {
 /// Code to process player input into camera movements
 fn camera_control(
     kbd: Res<ButtonInput<KeyCode>>,
-    // mut evr_motion: EventReader<MouseMotion>,
+    mut evr_motion: EventReader<MouseMotion>,
+    mode: Res<State<CameraMode>>,
     mut q_camera: Query<(&CameraSettings, &mut CameraAimState, &mut Transform)>,
 ) {
-    for (cam_settings, cam_state, mut transform) in &mut q_camera {
-        let debug_cam_move_l = KeyCode::KeyA;
-        let debug_cam_move_r = KeyCode::KeyT;
-        let debug_cam_move_u = KeyCode::KeyS;
-        let debug_cam_move_d = KeyCode::KeyR;
-        let debug_cam_move_f = KeyCode::KeyC;
-        let debug_cam_move_b = KeyCode::KeyD;
-        let debug_moving_up = kbd.pressed(debug_cam_move_u);
-        let debug_moving_down = kbd.pressed(debug_cam_move_d);
-        let debug_moving_right = kbd.pressed(debug_cam_move_r);
-        let debug_moving_left = kbd.pressed(debug_cam_move_l);
-        let debug_moving_fwd = kbd.pressed(debug_cam_move_f);
-        let debug_moving_back = kbd.pressed(debug_cam_move_b);
+    // Accumulate this frame's mouse motion; yaw tracks horizontal, pitch vertical.
+    let mut mouse_delta = Vec2::ZERO;
+    for motion in evr_motion.read() {
+        mouse_delta += motion.delta;
+    }
+
+    // Free-fly translation is only read when the debug camera mode is active.
+    let free_fly = *mode.get() == CameraMode::FreeFly;
 
+    for (cam_settings, cam_state, mut transform) in &mut q_camera {
         let pup = cam_settings.pitch_up_key;
         let pdn = cam_settings.pitch_down_key;
         let rleft = cam_settings.roll_left_key;
@@ -276,36 +538,43 @@ fn camera_control(
         let mut cam_roll = cam_state.roll;
         let mut pitch_total: f32 = 0.0;
         let mut roll_total: f32 = 0.0;
+        let mut yaw_total: f32 = 0.0;
+
+        // Mouse motion feeds yaw (horizontal) and pitch (vertical), scaled by sensitivity.
+        yaw_total -= mouse_delta.x * cam_settings.sensitivity;
+        pitch_total -= mouse_delta.y * cam_settings.sensitivity;
+
+        if free_fly {
+            let mov_rate = cam_settings.freefly_mov_rate;
+            let mut debug_mov_u_total: f32 = 0.0;
+            let mut debug_mov_r_total: f32 = 0.0;
+            let mut debug_mov_f_total: f32 = 0.0;
+            if kbd.pressed(cam_settings.freefly_up_key) {
+                debug_mov_u_total += mov_rate;
+            }
+            if kbd.pressed(cam_settings.freefly_down_key) {
+                debug_mov_u_total -= mov_rate;
+            }
+            if kbd.pressed(cam_settings.freefly_right_key) {
+                debug_mov_r_total += mov_rate;
+            }
+            if kbd.pressed(cam_settings.freefly_left_key) {
+                debug_mov_r_total -= mov_rate;
+            }
+            if kbd.pressed(cam_settings.freefly_fwd_key) {
+                debug_mov_f_total += mov_rate;
+            }
+            if kbd.pressed(cam_settings.freefly_back_key) {
+                debug_mov_f_total -= mov_rate;
+            }
 
-        let mut debug_mov_u_total: f32 = 0.0;
-        let mut debug_mov_r_total: f32 = 0.0;
-        let mut debug_mov_f_total: f32 = 0.0;
-        let debug_mov_rate: f32 = 0.01;
-        if debug_moving_up {
-            debug_mov_u_total += debug_mov_rate;
-        }
-        if debug_moving_down {
-            debug_mov_u_total -= debug_mov_rate;
-        }
-        if debug_moving_right {
-            debug_mov_r_total += debug_mov_rate;
-        }
-        if debug_moving_left {
-            debug_mov_r_total -= debug_mov_rate;
-        }
-        if debug_moving_fwd {
-            debug_mov_f_total += debug_mov_rate;
-        }
-        if debug_moving_back {
-            debug_mov_f_total -= debug_mov_rate;
-        }
-
-        if debug_mov_f_total != 0.0 || debug_mov_r_total != 0.0 || debug_mov_u_total != 0.0 {
-            let mov_rt = transform.right() * debug_mov_r_total;
-            let mov_up = transform.up() * debug_mov_u_total;
-            let mov_fwd = transform.forward() * debug_mov_f_total;
+            if debug_mov_f_total != 0.0 || debug_mov_r_total != 0.0 || debug_mov_u_total != 0.0 {
+                let mov_rt = transform.right() * debug_mov_r_total;
+                let mov_up = transform.up() * debug_mov_u_total;
+                let mov_fwd = transform.forward() * debug_mov_f_total;
 
-            transform.translation += mov_rt + mov_up + mov_fwd;
+                transform.translation += mov_rt + mov_up + mov_fwd;
+            }
         }
 
         if pitching_up {
@@ -324,8 +593,83 @@ fn camera_control(
         cam_pitch += pitch_total;
         cam_roll += roll_total;
 
-        if pitch_total != 0.0 || roll_total != 0.0 {
-            transform.rotate_local(Quat::from_euler(EulerRot::YXZ, 0.0, cam_pitch, cam_roll));
+        if pitch_total != 0.0 || roll_total != 0.0 || yaw_total != 0.0 {
+            // 6DOF: compose yaw/pitch with the accumulated roll on local axes so the
+            // controls stay correct even when the camera is banked.
+            transform.rotate_local(Quat::from_euler(EulerRot::YXZ, yaw_total, cam_pitch, cam_roll));
+        }
+    }
+}
+
+/// Cycle between the locked gameplay camera and the free-fly debug camera.
+fn toggle_camera_mode(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mode: Res<State<CameraMode>>,
+    q_settings: Query<&CameraSettings>,
+    mut next_mode: ResMut<NextState<CameraMode>>,
+) {
+    let Ok(cam_settings) = q_settings.get_single() else {
+        return;
+    };
+    if kbd.just_pressed(cam_settings.mode_toggle_key) {
+        next_mode.set(match *mode.get() {
+            CameraMode::Gameplay => CameraMode::FreeFly,
+            CameraMode::FreeFly => CameraMode::Gameplay,
+        });
+    }
+}
+
+/// Mouse-wheel zoom: adjust a target FOV within the configured clamps and lerp toward it.
+fn camera_zoom(
+    mut evr_wheel: EventReader<MouseWheel>,
+    mut q_camera: Query<(&CameraSettings, &mut CameraAimState, &mut Projection)>,
+) {
+    let mut scroll = 0.0;
+    for wheel in evr_wheel.read() {
+        scroll += wheel.y;
+    }
+
+    for (cam_settings, mut cam_state, mut projection) in &mut q_camera {
+        // Scrolling up zooms in (narrower FOV).
+        cam_state.target_fov = (cam_state.target_fov - scroll * cam_settings.zoom_rate)
+            .clamp(cam_settings.fov_min, cam_settings.fov_max);
+
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = perspective.fov.lerp(cam_state.target_fov, 0.2);
+        }
+    }
+}
+
+/// Lock and hide the OS cursor at startup so mouse-look feels like an FPS.
+fn grab_cursor(mut q_window: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = q_window.get_single_mut() {
+        window.cursor_options.grab_mode = CursorGrabMode::Locked;
+        window.cursor_options.visible = false;
+    }
+}
+
+/// Toggle the cursor grab/visibility so the player can release the mouse while aiming.
+fn toggle_cursor_grab(
+    kbd: Res<ButtonInput<KeyCode>>,
+    q_settings: Query<&CameraSettings>,
+    mut q_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(cam_settings) = q_settings.get_single() else {
+        return;
+    };
+    if !kbd.just_pressed(cam_settings.cursor_toggle_key) {
+        return;
+    }
+    if let Ok(mut window) = q_window.get_single_mut() {
+        match window.cursor_options.grab_mode {
+            CursorGrabMode::Locked => {
+                window.cursor_options.grab_mode = CursorGrabMode::None;
+                window.cursor_options.visible = true;
+            }
+            _ => {
+                window.cursor_options.grab_mode = CursorGrabMode::Locked;
+                window.cursor_options.visible = false;
+            }
         }
     }
 }
@@ -349,6 +693,11 @@ fn aim_check(
         &MyBoundingSphere,
     )>,
     my_assets: Res<MyAssets>,
+    music: Res<MusicState>,
+    time: Res<Time>,
+    mut combo: ResMut<ComboState>,
+    mut dwell: ResMut<ActiveDwell>,
+    mut evw_feedback: EventWriter<FeedbackEvent>,
 ) {
     if let Ok(transform) = q_camera.get_single() {
         let aim = transform.forward();
@@ -357,6 +706,7 @@ fn aim_check(
         let ray = RayCast3d::new(transform.translation, aim, MAX_RADIUS);
         let mut hit = false;
         let mut active_id: Option<Entity> = None;
+        let mut active_pos: Option<Vec3> = None;
         let mut next_state: Option<Mut<TargetState>> = None;
         let mut next_mat: Option<Mut<MeshMaterial3d<StandardMaterial>>> = None;
         let mut ghost_state: Option<(Mut<Transform>, Mut<TargetState>, Mut<Visibility>)> = None;
@@ -365,6 +715,7 @@ fn aim_check(
             match *state {
                 TargetState::Active => {
                     active_id = Some(id);
+                    active_pos = Some(transform.translation);
                     // active_state = Some(*state);
                     if ray.intersects(&bounding.0) {
                         hit = true;
@@ -401,18 +752,86 @@ fn aim_check(
                     let new_ghost_pos = target_hit(
                         commands,
                         my_assets,
+                        &music,
                         active_id.unwrap(),
                         Some(old_ghost_transform.translation),
                     );
 
                     // Point new next at new ghost
                     orient_target(&mut old_ghost_transform, new_ghost_pos);
+
+                    // Feedback is decoupled from cycling: announce the hit and any milestone.
+                    combo.count += 1;
+                    if let Some(at) = active_pos {
+                        evw_feedback.send(FeedbackEvent::TargetHit { at });
+                    }
+                    if combo.count % COMBO_MILESTONE == 0 {
+                        evw_feedback.send(FeedbackEvent::Combo { count: combo.count });
+                    }
+
+                    // Fresh target: restart its dwell clock.
+                    dwell.timer.reset();
                 } else {
                     panic!("Ghost target missing!");
                 }
             } else {
                 panic!("Next target missing!");
             }
+        } else {
+            // No hit this frame: the Active target is dwelling unhit. If it outlives its
+            // window the player missed it, so break the streak and announce the miss.
+            dwell.timer.tick(time.delta());
+            if dwell.timer.just_finished() {
+                evw_feedback.send(FeedbackEvent::TargetMissed);
+                combo.count = 0;
+            }
+        }
+    }
+}
+
+/// Play a randomly chosen clip from the category's pool and pop a glowing mesh at each hit.
+fn play_feedback(
+    mut commands: Commands,
+    mut evr_feedback: EventReader<FeedbackEvent>,
+    feedback: Res<FeedbackAssets>,
+    my_assets: Res<MyAssets>,
+) {
+    let mut gen = rand::thread_rng();
+    for event in evr_feedback.read() {
+        if let Some(clips) = feedback.clips.get(&FeedbackCategory::of(event)) {
+            if !clips.is_empty() {
+                let clip = clips[gen.gen_range(0..clips.len())].clone();
+                commands.spawn((AudioPlayer(clip), PlaybackSettings::DESPAWN));
+            }
+        }
+
+        if let FeedbackEvent::TargetHit { at } = event {
+            commands.spawn((
+                Shape {
+                    mesh: Mesh3d(my_assets.debug_target_mesh.clone()),
+                    material: MeshMaterial3d(my_assets.debug_material.clone()),
+                    transform: Transform::from_translation(*at).with_scale(Vec3::splat(0.2)),
+                    visibility: Visibility::Visible,
+                },
+                Pop {
+                    timer: Timer::from_seconds(0.25, TimerMode::Once),
+                },
+            ));
+        }
+    }
+}
+
+/// Grow each pop over its lifetime, then despawn it.
+fn update_pops(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_pop: Query<(Entity, &mut Pop, &mut Transform)>,
+) {
+    for (id, mut pop, mut transform) in &mut q_pop {
+        pop.timer.tick(time.delta());
+        transform.scale = Vec3::splat(0.2 + pop.timer.fraction() * 1.5);
+        if pop.timer.finished() {
+            commands.entity(id).despawn_recursive();
         }
     }
 }
@@ -420,11 +839,11 @@ fn aim_check(
 fn target_hit(
     mut commands: Commands,
     my_assets: Res<MyAssets>,
+    music: &MusicState,
     hit_target_id: Entity,
     deadzone: Option<Vec3>,
 ) -> Vec3 {
-    // Trigger any visual or audio effects on hit, play fade animation
-    // Spawn note-after-next
+    // Feedback is handled via FeedbackEvent; here we just cycle the note-after-next in.
     commands.entity(hit_target_id).despawn_recursive();
     spawn_target(
         &mut commands,
@@ -432,6 +851,7 @@ fn target_hit(
         TargetState::Ghost,
         None,
         deadzone,
+        music,
     )
 }
 
@@ -441,18 +861,27 @@ fn spawn_target(
     state: TargetState,
     aim_point: Option<Vec3>,
     deadzone: Option<Vec3>,
+    music: &MusicState,
 ) -> Vec3 {
-    let mut target_center = random_normalized_vec3() * TARGET_DISTANCE;
+    let mut target_center = next_note_direction(music) * TARGET_DISTANCE;
 
     if let Some(deadzone) = deadzone {
-        let btwn = target_center - deadzone;
-        let distance_sq = btwn.length_squared();
-        println!("dist is {:?} before adjustment", distance_sq);
-        if distance_sq < DEADZONE_RADIUS_SQUARED {
-            let rot_axis = btwn.cross(target_center);
-            let rot = Quat::from_axis_angle(rot_axis, DEADZONE_ADJ_THETA);
-            target_center = rot.mul_vec3(target_center);
-            println!("dist is {:?} after adjustment", distance_sq);
+        // Consecutive notes come from a small discrete set, so a fresh sample often lands on
+        // the previous target. Re-sample (then nudge, once the note-set is exhausted) until
+        // the new center actually clears the deadzone.
+        let mut attempts = 0;
+        while (target_center - deadzone).length_squared() < DEADZONE_RADIUS_SQUARED {
+            if attempts < DEADZONE_MAX_RESAMPLES {
+                target_center = next_note_direction(music) * TARGET_DISTANCE;
+            } else {
+                let mut rot_axis = deadzone.cross(target_center);
+                if rot_axis.length_squared() < 1e-6 {
+                    rot_axis = Vec3::Y;
+                }
+                let rot = Quat::from_axis_angle(rot_axis.normalize(), DEADZONE_ADJ_THETA.abs());
+                target_center = rot.mul_vec3(target_center);
+            }
+            attempts += 1;
         }
     }
 
@@ -498,14 +927,24 @@ fn orient_target(transform: &mut Transform, aim_point: Vec3) {
     transform.align(Dir3::X, dir_to_center, Dir3::Y, aim_point);
 }
 
-// fn next_note() {
-// Code to semi-randomly determine the next musical note in the progression,
-// and use that to determine where the next target will spawn
-// let base = chord.base_note;
-// }
-
-// fn background_music() {
-// Might not want to be a function, but this should handle the background music,
-// which should be a sensible chord progression that gates the possible values for
-// next_note. Also maybe a rising shepherd tone?
-// }
+/// Advance the progression on a bar-length timer synced to the BPM, playing each chord
+/// so the background music and the target layout stay coupled.
+fn advance_chord(
+    time: Res<Time>,
+    bpm: Res<Bpm>,
+    mut commands: Commands,
+    mut music: ResMut<MusicState>,
+) {
+    let bar_seconds = 4.0 * 60.0 / bpm.0;
+    music
+        .timer
+        .set_duration(Duration::from_secs_f32(bar_seconds));
+    music.timer.tick(time.delta());
+    if music.timer.just_finished() {
+        let next = (music.index + 1) % music.progression.len();
+        music.index = next;
+        if let Some(clip) = music.chord_audio.get(next) {
+            commands.spawn((AudioPlayer(clip.clone()), PlaybackSettings::DESPAWN));
+        }
+    }
+}